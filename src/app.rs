@@ -5,17 +5,20 @@ use winit::window::{Window, WindowId};
 
 use std::sync::Arc;
 
-use crate::renderer::Renderer;
+use crate::renderer::{PresentModePreference, Renderer};
+use crate::scene::{Color, Position, Scene};
 
 pub struct App {
     window: Option<Arc<Window>>,
     renderer: Option<Renderer>,
+    scene: Scene,
 }
 
 impl Default for App {
     fn default() -> Self {
         Self { window: None,
-               renderer: None, }
+               renderer: None,
+               scene: Scene::new(), }
     }
 }
 
@@ -28,8 +31,21 @@ impl ApplicationHandler for App {
         );
 
         self.window = Some(window.clone());
-        self.renderer = Some(Renderer::new(window));
+        let mut renderer = Renderer::new(window, PresentModePreference::default());
 
+        // Enable live editing: watch the shaders directory and scene config so
+        // edits repaint without a restart. A missing path is non-fatal — the app
+        // just runs without hot-reload.
+        if let Err(e) = renderer.watch_assets("shaders", "scene.json") {
+            eprintln!("asset hot-reload disabled: {e}");
+        }
+
+        self.renderer = Some(renderer);
+
+        // Seed the world with a single visible point at the origin.
+        let origin = self.scene.spawn(Position { x: 0.0, y: 0.0 });
+        self.scene.set_color(origin, Color { r: 1.0, g: 1.0, b: 1.0 });
+        self.scene.set_renderable(origin, true);
     }
 
     fn window_event(&mut self, 
@@ -42,9 +58,19 @@ impl ApplicationHandler for App {
                 println!("The close button was pressed; stopping");
                 event_loop.exit();
             },
+            WindowEvent::Resized(_) => {
+                if let Some(renderer) = self.renderer.as_mut() {
+                    renderer.resize();
+                }
+            }
             WindowEvent::RedrawRequested => {
                 if let Some(renderer) = self.renderer.as_mut() {
-                    renderer.render().unwrap();
+                    // Recoverable device errors (a stale swapchain, a transient
+                    // timeout) should log and let the loop carry on rather than
+                    // take the whole process down.
+                    if let Err(e) = renderer.render(&self.scene) {
+                        eprintln!("render error: {e}");
+                    }
                 }
             }
             _ => (),
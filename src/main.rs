@@ -1,8 +1,9 @@
 mod app;
+mod renderer;
+mod scene;
 
 use app::App;
 
-use std::{error::Error, sync::Arc};
 use winit::event_loop::EventLoop;
 
 
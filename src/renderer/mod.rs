@@ -0,0 +1,3 @@
+mod vulkan;
+
+pub use vulkan::{PresentModePreference, Point, Renderer, RendererError};
@@ -1,6 +1,4 @@
-use vulkano::sync::now;
-
-use vulkano::device::{  Device, 
+use vulkano::device::{  Device,
                         DeviceCreateInfo, 
                         DeviceExtensions, 
                         QueueCreateInfo, 
@@ -11,23 +9,49 @@ use vulkano::image::{ ImageUsage };
 use vulkano::image::view::{ ImageView };
 
 use vulkano::sync::GpuFuture;
+use vulkano::sync::Sharing;
 use vulkano::command_buffer::{
-        AutoCommandBufferBuilder, 
-        CommandBufferUsage, 
-        RenderPassBeginInfo, 
+        AutoCommandBufferBuilder,
+        CommandBufferUsage,
+        CopyBufferInfo,
+        RenderPassBeginInfo,
         SubpassContents,
         SubpassEndInfo,
     };
 
 use vulkano::command_buffer::allocator::StandardCommandBufferAllocator;
+use vulkano::command_buffer::CommandBufferExecFuture;
+use vulkano::sync::future::{FenceSignalFuture, NowFuture};
+
+use vulkano::buffer::{Buffer, BufferCreateInfo, BufferUsage, Subbuffer};
+use vulkano::memory::allocator::{AllocationCreateInfo, MemoryTypeFilter, StandardMemoryAllocator};
+
+use vulkano::pipeline::graphics::color_blend::{ColorBlendAttachmentState, ColorBlendState};
+use vulkano::pipeline::graphics::input_assembly::{InputAssemblyState, PrimitiveTopology};
+use vulkano::pipeline::graphics::multisample::MultisampleState;
+use vulkano::pipeline::graphics::rasterization::RasterizationState;
+use vulkano::pipeline::graphics::vertex_input::{Vertex, VertexDefinition};
+use vulkano::pipeline::graphics::viewport::{Viewport, ViewportState};
+use vulkano::pipeline::graphics::GraphicsPipelineCreateInfo;
+use vulkano::pipeline::layout::PipelineDescriptorSetLayoutCreateInfo;
+use vulkano::pipeline::{
+        DynamicState,
+        GraphicsPipeline,
+        Pipeline,
+        PipelineLayout,
+        PipelineShaderStageCreateInfo,
+    };
+
+use vulkano::buffer::BufferContents;
 
 use vulkano::instance::{Instance, InstanceCreateInfo};
-use vulkano::render_pass::{Framebuffer, FramebufferCreateInfo, RenderPass};
+use vulkano::render_pass::{Framebuffer, FramebufferCreateInfo, RenderPass, Subpass};
 use vulkano::swapchain::{
-        Surface, 
-        SurfaceInfo, 
-        Swapchain, 
-        SwapchainCreateInfo, 
+        self,
+        Surface,
+        SurfaceInfo,
+        Swapchain,
+        SwapchainCreateInfo,
         PresentMode,
         SwapchainPresentInfo
     };
@@ -35,11 +59,344 @@ use vulkano::swapchain::{
 use vulkano::format::ClearValue;
 
 use vulkano::{
-        Version, 
-        VulkanLibrary 
+        Validated,
+        Version,
+        VulkanError,
+        VulkanLibrary
     };
 
+use vulkano::shader::ShaderModule;
+#[cfg(feature = "shader-reload")]
+use vulkano::shader::ShaderModuleCreateInfo;
+
+use notify_debouncer_mini::notify::RecursiveMode;
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use notify_debouncer_mini::notify::RecommendedWatcher;
+
+use serde::Deserialize;
+
+use crate::scene::{Color, Scene};
+
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Errors surfaced by [`Renderer::render`].
+///
+/// `NeedsRecreate` is the one non-fatal variant: it means the swapchain went
+/// out of date (typically a resize) and a rebuild is already queued, so the
+/// caller can simply log it and carry on. Every other variant wraps a genuine
+/// device-side failure from a distinct stage of the frame.
+#[derive(Debug)]
+pub enum RendererError {
+    /// Swapchain out of date or suboptimal; recreation is pending.
+    NeedsRecreate,
+    /// Acquiring the next swapchain image failed.
+    Acquire(VulkanError),
+    /// Recording or building the command buffer failed.
+    Record(Box<dyn Error + Send + Sync>),
+    /// Submitting work to the graphics queue failed.
+    Submit(Box<dyn Error + Send + Sync>),
+    /// Presenting the finished frame failed.
+    Present(VulkanError),
+    /// Rebuilding the swapchain on resize failed fatally (e.g. surface lost).
+    Recreate(VulkanError),
+    /// Streaming new vertex data to the GPU failed.
+    Upload(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for RendererError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RendererError::NeedsRecreate => write!(f, "swapchain needs recreation"),
+            RendererError::Acquire(e) => write!(f, "failed to acquire next image: {e}"),
+            RendererError::Record(e) => write!(f, "failed to record command buffer: {e}"),
+            RendererError::Submit(e) => write!(f, "failed to submit frame: {e}"),
+            RendererError::Present(e) => write!(f, "failed to present frame: {e}"),
+            RendererError::Recreate(e) => write!(f, "failed to recreate swapchain: {e}"),
+            RendererError::Upload(e) => write!(f, "failed to upload vertex data: {e}"),
+        }
+    }
+}
+
+impl Error for RendererError {}
+
+/// A single world point handed to the renderer.
+///
+/// Laid out for direct upload into a `PointList` vertex buffer: a clip-space
+/// `position` plus an RGB `color`.
+#[derive(BufferContents, Vertex, Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Point {
+    #[format(R32G32_SFLOAT)]
+    pub position: [f32; 2],
+    #[format(R32G32B32_SFLOAT)]
+    pub color: [f32; 3],
+}
+
+// GLSL source is compiled at build time by `vulkano_shaders` and exposed as
+// typed `load`/entry-point bindings under these modules.
+mod vs {
+    vulkano_shaders::shader! {
+        ty: "vertex",
+        src: r"
+            #version 450
+
+            layout(location = 0) in vec2 position;
+            layout(location = 1) in vec3 color;
+
+            layout(location = 0) out vec3 v_color;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+                gl_PointSize = 1.0;
+                v_color = color;
+            }
+        ",
+    }
+}
+
+mod fs {
+    vulkano_shaders::shader! {
+        ty: "fragment",
+        src: r"
+            #version 450
+
+            layout(location = 0) in vec3 v_color;
+            layout(location = 0) out vec4 f_color;
+
+            void main() {
+                f_color = vec4(v_color, 1.0);
+            }
+        ",
+    }
+}
+
+// Build the point-drawing pipeline from the shaders baked in at compile time.
+fn build_pipeline(device: Arc<Device>, render_pass: Arc<RenderPass>) -> Arc<GraphicsPipeline> {
+    let vs = vs::load(device.clone()).unwrap();
+    let fs = fs::load(device.clone()).unwrap();
+    build_pipeline_from_modules(device, render_pass, vs, fs)
+}
+
+// Build the point-drawing pipeline against `render_pass` from the given shader
+// modules. The viewport is left dynamic so a swapchain recreate never has to
+// rebuild this pipeline, and a shader hot-reload only has to rebuild this one.
+fn build_pipeline_from_modules(
+    device: Arc<Device>,
+    render_pass: Arc<RenderPass>,
+    vs_module: Arc<ShaderModule>,
+    fs_module: Arc<ShaderModule>,
+) -> Arc<GraphicsPipeline> {
+    let vs = vs_module
+        .entry_point("main")
+        .unwrap();
+    let fs = fs_module
+        .entry_point("main")
+        .unwrap();
+
+    let vertex_input_state = Point::per_vertex()
+        .definition(&vs.info().input_interface)
+        .unwrap();
+
+    let stages = [
+        PipelineShaderStageCreateInfo::new(vs),
+        PipelineShaderStageCreateInfo::new(fs),
+    ];
+
+    let layout = PipelineLayout::new(
+        device.clone(),
+        PipelineDescriptorSetLayoutCreateInfo::from_stages(&stages)
+            .into_pipeline_layout_create_info(device.clone())
+            .unwrap(),
+    )
+    .unwrap();
+
+    let subpass = Subpass::from(render_pass, 0).unwrap();
+
+    GraphicsPipeline::new(
+        device,
+        None,
+        GraphicsPipelineCreateInfo {
+            stages: stages.into_iter().collect(),
+            vertex_input_state: Some(vertex_input_state),
+            input_assembly_state: Some(InputAssemblyState {
+                topology: PrimitiveTopology::PointList,
+                ..Default::default()
+            }),
+            viewport_state: Some(ViewportState::default()),
+            rasterization_state: Some(RasterizationState::default()),
+            multisample_state: Some(MultisampleState::default()),
+            color_blend_state: Some(ColorBlendState::with_attachment_states(
+                subpass.num_color_attachments(),
+                ColorBlendAttachmentState::default(),
+            )),
+            dynamic_state: [DynamicState::Viewport].into_iter().collect(),
+            subpass: Some(subpass.into()),
+            ..GraphicsPipelineCreateInfo::layout(layout)
+        },
+    )
+    .unwrap()
+}
+
+/// A debounced filesystem change relevant to live editing.
+#[derive(Debug, Clone)]
+enum ReloadEvent {
+    /// A GLSL source file under the watched shaders directory changed.
+    Shader,
+    /// The scene config file changed.
+    Config,
+}
+
+/// The on-disk scene description reloaded when the config file changes.
+///
+/// Deliberately small: a shaders directory, the world points to draw, and the
+/// background color.
+#[derive(Debug, Clone, Deserialize)]
+struct SceneConfig {
+    /// Directory holding `point.vert` / `point.frag`. Parsed but not applied:
+    /// shaders are watched and reloaded from the directory given to
+    /// [`Renderer::watch_assets`].
+    shaders_dir: PathBuf,
+    /// World points, in clip space.
+    points: Vec<ConfigPoint>,
+    /// Render-pass clear color (RGBA).
+    clear_color: [f32; 4],
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ConfigPoint {
+    position: [f32; 2],
+    #[serde(default = "default_point_color")]
+    color: [f32; 3],
+}
+
+fn default_point_color() -> [f32; 3] {
+    [1.0, 1.0, 1.0]
+}
+
+impl ConfigPoint {
+    fn to_point(&self) -> Point {
+        Point {
+            position: self.position,
+            color: self.color,
+        }
+    }
+}
+
+// Keeps the debouncer alive and hands change events to the render loop.
+struct ReloadWatcher {
+    _debouncer: Debouncer<RecommendedWatcher>,
+    events: Receiver<ReloadEvent>,
+    // Only read when recompiling shaders at runtime.
+    #[cfg_attr(not(feature = "shader-reload"), allow(dead_code))]
+    shaders_dir: PathBuf,
+    config_path: PathBuf,
+}
+
+impl ReloadWatcher {
+    // Start watching `shaders_dir` and `config_path`; on any debounced change,
+    // classify it and nudge the window so `ControlFlow::Wait` repaints at once.
+    fn new(
+        shaders_dir: PathBuf,
+        config_path: PathBuf,
+        window: Arc<Window>,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let (tx, rx) = channel();
+        let config_for_cb = config_path.clone();
+
+        let mut debouncer = new_debouncer(
+            Duration::from_millis(250),
+            move |result: DebounceEventResult| {
+                let Ok(events) = result else { return };
+                let mut touched = false;
+                for event in events {
+                    let kind = if event.path == config_for_cb {
+                        ReloadEvent::Config
+                    } else {
+                        ReloadEvent::Shader
+                    };
+                    if tx.send(kind).is_ok() {
+                        touched = true;
+                    }
+                }
+                // Wake the event loop so the reload paints immediately.
+                if touched {
+                    window.request_redraw();
+                }
+            },
+        )?;
+
+        debouncer
+            .watcher()
+            .watch(&shaders_dir, RecursiveMode::Recursive)?;
+        debouncer
+            .watcher()
+            .watch(&config_path, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            _debouncer: debouncer,
+            events: rx,
+            shaders_dir,
+            config_path,
+        })
+    }
+}
+
+// Compile a single GLSL stage to SPIR-V at runtime via shaderc.
+#[cfg(feature = "shader-reload")]
+fn compile_shader(
+    source: &str,
+    kind: shaderc::ShaderKind,
+    name: &str,
+) -> Result<Vec<u32>, Box<dyn Error + Send + Sync>> {
+    let compiler = shaderc::Compiler::new().ok_or("failed to create shaderc compiler")?;
+    let artifact = compiler.compile_into_spirv(source, kind, name, "main", None)?;
+    Ok(artifact.as_binary().to_vec())
+}
+
+// Recompile `point.vert` / `point.frag` from `shaders_dir` and turn them into
+// vulkano shader modules ready to feed a fresh pipeline.
+#[cfg(feature = "shader-reload")]
+fn load_shader_modules(
+    device: Arc<Device>,
+    shaders_dir: &Path,
+) -> Result<(Arc<ShaderModule>, Arc<ShaderModule>), Box<dyn Error + Send + Sync>> {
+    let vert_src = std::fs::read_to_string(shaders_dir.join("point.vert"))?;
+    let frag_src = std::fs::read_to_string(shaders_dir.join("point.frag"))?;
+
+    let vert_spirv = compile_shader(&vert_src, shaderc::ShaderKind::Vertex, "point.vert")?;
+    let frag_spirv = compile_shader(&frag_src, shaderc::ShaderKind::Fragment, "point.frag")?;
+
+    // SAFETY: the SPIR-V came straight out of shaderc for the requested stages.
+    let vs = unsafe { ShaderModule::new(device.clone(), ShaderModuleCreateInfo::new(&vert_spirv))? };
+    let fs = unsafe { ShaderModule::new(device, ShaderModuleCreateInfo::new(&frag_spirv))? };
+
+    Ok((vs, fs))
+}
+
+/// How the swapchain should trade latency against tearing.
+///
+/// Maps to Vulkan present modes, but degrades gracefully: whatever the
+/// preference asks for, `Fifo` is always available as a fallback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PresentModePreference {
+    /// Classic vsync, no tearing. Always `Fifo`.
+    Vsync,
+    /// Prefer `Mailbox` (low latency, no tearing) when the surface supports it.
+    LowLatency,
+    /// Prefer `Immediate` (uncapped, may tear) for maximum throughput.
+    Uncapped,
+}
+
+impl Default for PresentModePreference {
+    fn default() -> Self {
+        PresentModePreference::Vsync
+    }
+}
 
 pub struct Renderer {
     inst: RenderInstance,
@@ -48,16 +405,299 @@ pub struct Renderer {
 use winit::window::Window;
 
 impl Renderer {
-    pub fn new( window: Arc<Window> ) -> Self {
+    pub fn new( window: Arc<Window>, present_mode: PresentModePreference ) -> Self {
         // build RenderInstance
-        Self { inst: RenderInstance::new( window ) }
+        Self { inst: RenderInstance::new( window, present_mode ) }
     }
 
     // expose *intent*, not guts
     pub fn request_redraw(&self) { }
 
-    pub fn render(&mut self) {
+    // expose *intent*: the window was resized, so the swapchain is stale
+    pub fn resize(&mut self) {
+        self.inst.recreate_swapchain = true;
+    }
+
+    // Replace the world geometry drawn each frame. Geometry is driven entirely
+    // by the scene/config render system (see `render`), so this is an internal
+    // upload step rather than a public API.
+    //
+    // Streams `points` into the *back* buffer on the transfer queue while the
+    // front buffer keeps being drawn. The swap is deferred to a later frame, once
+    // the upload's fence has signalled (see [`VertexBuffers::try_promote`]), so
+    // the draw never binds a buffer the transfer queue is still writing. An empty
+    // slice clears the geometry.
+    //
+    // Returns `true` when the upload was started (or the geometry was cleared),
+    // `false` when an earlier upload is still in flight and the caller should
+    // retry on a later frame.
+    fn upload_points(&mut self, points: &[Point]) -> Result<bool, RendererError> {
+        let inst = &mut self.inst;
+
+        if points.is_empty() {
+            inst.vertex_buffers.clear();
+            return Ok(true);
+        }
+
+        // Only one upload may be in flight: while the back slot's previous copy
+        // is still running or waiting to be promoted, leave the geometry dirty so
+        // this retries once the slot is free again.
+        if inst.vertex_buffers.has_pending() {
+            return Ok(false);
+        }
+
+        let need = points.len() as u64;
+        // Stream into the back slot — never the one currently being drawn.
+        let back = 1 - inst.vertex_buffers.front;
+
+        // Host-visible staging copy of the new point data.
+        let staging = Buffer::from_iter(
+            inst.memory_allocator.clone(),
+            BufferCreateInfo {
+                usage: BufferUsage::TRANSFER_SRC,
+                ..Default::default()
+            },
+            AllocationCreateInfo {
+                memory_type_filter: MemoryTypeFilter::PREFER_HOST
+                    | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+                ..Default::default()
+            },
+            points.iter().copied(),
+        )
+        .map_err(|e| RendererError::Upload(Box::new(e)))?;
+
+        // Reuse the long-lived back buffer when it's large enough; only grow it
+        // when a larger point set arrives, so steady-state streaming does no
+        // per-frame allocation.
+        let reuse = matches!(
+            &inst.vertex_buffers.slots[back],
+            Some(slot) if slot.buffer.len() >= need
+        );
+        if !reuse {
+            // The back buffer is written on the transfer queue and read on the
+            // graphics queue. When those are distinct families, declare concurrent
+            // sharing so no queue-family ownership transfer is required (otherwise
+            // the graphics queue would see undefined contents).
+            let graphics_family = inst.queue.queue_family_index();
+            let transfer_family = inst.transfer_queue.queue_family_index();
+            let sharing = if graphics_family == transfer_family {
+                Sharing::Exclusive
+            } else {
+                Sharing::Concurrent([graphics_family, transfer_family].into_iter().collect())
+            };
+
+            let buffer = Buffer::new_slice::<Point>(
+                inst.memory_allocator.clone(),
+                BufferCreateInfo {
+                    usage: BufferUsage::VERTEX_BUFFER | BufferUsage::TRANSFER_DST,
+                    sharing,
+                    ..Default::default()
+                },
+                AllocationCreateInfo {
+                    memory_type_filter: MemoryTypeFilter::PREFER_DEVICE,
+                    ..Default::default()
+                },
+                need,
+            )
+            .map_err(|e| RendererError::Upload(Box::new(e)))?;
+
+            inst.vertex_buffers.slots[back] = Some(VertexSlot { buffer, len: 0 });
+        }
+
+        // Copy into the first `need` elements; the buffer itself may be larger.
+        let region = inst.vertex_buffers.slots[back]
+            .as_ref()
+            .unwrap()
+            .buffer
+            .clone()
+            .slice(0..need);
+
+        // Record and submit the staging -> device copy on the transfer queue so
+        // it runs concurrently with the graphics queue drawing the front buffer.
+        let mut builder = AutoCommandBufferBuilder::primary(
+            &inst.command_buffer_allocator,
+            inst.transfer_queue.queue_family_index(),
+            CommandBufferUsage::OneTimeSubmit,
+        )
+        .map_err(|e| RendererError::Upload(Box::new(e)))?;
+
+        builder
+            .copy_buffer(CopyBufferInfo::buffers(staging, region))
+            .map_err(|e| RendererError::Upload(Box::new(e)))?;
+
+        let command_buffer = builder
+            .build()
+            .map_err(|e| RendererError::Upload(Box::new(e)))?;
+
+        // Signal a fence we poll in `try_promote`, rather than chaining this
+        // future into the graphics submit: the swap is deferred until the copy
+        // completes, so the front buffer can be drawn uninterrupted meanwhile.
+        let future = vulkano::sync::now(inst.device.clone())
+            .then_execute(inst.transfer_queue.clone(), command_buffer)
+            .map_err(|e| RendererError::Upload(Box::new(e)))?
+            .then_signal_fence_and_flush()
+            .map_err(|e| RendererError::Upload(Box::new(e)))?;
+
+        inst.vertex_buffers.pending = Some(PendingUpload {
+            future,
+            slot: back,
+            len: points.len() as u32,
+        });
+        Ok(true)
+    }
+
+    /// Begin watching a shaders directory and a scene config file for live
+    /// edits. The current scene is loaded immediately; subsequent changes are
+    /// picked up by [`Renderer::render`] without restarting the app.
+    pub fn watch_assets(
+        &mut self,
+        shaders_dir: impl Into<PathBuf>,
+        config_path: impl Into<PathBuf>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let config_path = config_path.into();
+
+        // Load the scene once up front so there's something to draw pre-edit.
+        self.apply_config(&config_path);
+
+        let watcher =
+            ReloadWatcher::new(shaders_dir.into(), config_path, self.inst.window.clone())?;
+        self.inst.reload = Some(watcher);
+        Ok(())
+    }
+
+    // Drain any debounced filesystem events and apply them: shader changes
+    // rebuild the pipeline, config changes reparse the scene. The swapchain,
+    // render pass and framebuffers are left untouched.
+    fn process_reloads(&mut self) {
+        let Some(reload) = self.inst.reload.as_ref() else {
+            return;
+        };
+
+        // Collapse the burst into at most one shader and one config reload.
+        let mut reload_shaders = false;
+        let mut reload_config = false;
+        while let Ok(event) = reload.events.try_recv() {
+            match event {
+                ReloadEvent::Shader => reload_shaders = true,
+                ReloadEvent::Config => reload_config = true,
+            }
+        }
+
+        if reload_shaders {
+            #[cfg(feature = "shader-reload")]
+            {
+                let shaders_dir = reload.shaders_dir.clone();
+                match load_shader_modules(self.inst.device.clone(), &shaders_dir) {
+                    Ok((vs, fs)) => {
+                        if let Some(rcx) = self.inst.rcx.as_mut() {
+                            rcx.pipeline = build_pipeline_from_modules(
+                                self.inst.device.clone(),
+                                rcx.render_pass.clone(),
+                                vs,
+                                fs,
+                            );
+                            println!("reloaded shaders from {}", shaders_dir.display());
+                        }
+                    }
+                    Err(e) => eprintln!("shader reload failed: {e}"),
+                }
+            }
+            #[cfg(not(feature = "shader-reload"))]
+            eprintln!(
+                "shader file changed but the `shader-reload` feature is disabled; \
+                 rebuild with --features shader-reload to enable runtime recompilation"
+            );
+        }
+
+        if reload_config {
+            let config_path = reload.config_path.clone();
+            self.apply_config(&config_path);
+        }
+    }
+
+    // Parse the scene config, apply its clear color and store its points for
+    // render() to upload.
+    fn apply_config(&mut self, path: &Path) {
+        let config = match std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str::<SceneConfig>(&text).map_err(|e| e.to_string()))
+        {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("scene config reload failed ({}): {e}", path.display());
+                return;
+            }
+        };
+
+        self.inst.clear_color = config.clear_color;
+        // Store the config geometry; render() uploads it in preference to the
+        // scene-derived geometry rather than both fighting over the buffer.
+        self.inst.config_points =
+            Some(config.points.iter().map(ConfigPoint::to_point).collect());
+        let _ = config.shaders_dir; // shaders are watched separately
+    }
+
+    pub fn render(&mut self, scene: &Scene) -> Result<(), RendererError> {
+        self.process_reloads();
+
+        // Render system: geometry hot-loaded from the scene config wins; absent
+        // that, fold the scene's renderable components into vertices. Either way
+        // world state stays entirely Vulkan-free.
+        let points: Vec<Point> = match &self.inst.config_points {
+            Some(config_points) => config_points.clone(),
+            None => scene
+                .renderables()
+                .map(|(position, color)| {
+                    let Color { r, g, b } = color.copied().unwrap_or(Color {
+                        r: 1.0,
+                        g: 1.0,
+                        b: 1.0,
+                    });
+                    Point {
+                        position: [position.x, position.y],
+                        color: [r, g, b],
+                    }
+                })
+                .collect(),
+        };
+
+        // Dirty check: only re-upload when the geometry actually changed, so the
+        // double-buffered transfer path runs on change — not every frame.
+        if self.inst.uploaded_points.as_deref() != Some(points.as_slice()) {
+            // Only mark the geometry as uploaded once the transfer actually
+            // started; if the back buffer is busy the upload retries next frame.
+            if self.upload_points(&points)? {
+                self.inst.uploaded_points = Some(points);
+            }
+        }
+
         let inst = &mut self.inst;
+
+        // 0) If the swapchain is stale (resize / suboptimal / out-of-date from a
+        //    previous frame), rebuild it before touching it again.
+        if inst.recreate_swapchain {
+            let window_size = inst.window.inner_size();
+            if window_size.width == 0 || window_size.height == 0 {
+                // Minimised or zero-sized; nothing worth drawing this frame.
+                return Ok(());
+            }
+
+            let rcx = inst.rcx.as_mut().expect("RenderContext not initialized");
+            match rcx
+                .recreate([window_size.width, window_size.height])
+                .map_err(Validated::unwrap)
+            {
+                // Rebuilt cleanly; drop the stale flag.
+                Ok(()) => inst.recreate_swapchain = false,
+                // Still out of date (e.g. another resize landed mid-rebuild).
+                // Leave the flag set so the next frame tries again.
+                Err(VulkanError::OutOfDate) => return Err(RendererError::NeedsRecreate),
+                // Anything else is fatal for this frame; keep the flag set so a
+                // later frame can retry if the condition clears.
+                Err(e) => return Err(RendererError::Recreate(e)),
+            }
+        }
+
         let rcx = inst.rcx.as_ref().expect("RenderContext not initialized");
 
         // 1) Clean up GPU work from previous frames
@@ -65,17 +705,31 @@ impl Renderer {
             fut.cleanup_finished();
         }
 
-        // 2) Acquire the next swapchain image to render into
-        let (image_index, _suboptimal, acquire_future) =
-            match swapchain::acquire_next_image(rcx.swapchain.clone(), None) {
+        // 2) Acquire the next swapchain image to render into. An out-of-date
+        //    swapchain is recoverable: flag a recreate and report it so the
+        //    caller can skip the frame instead of crashing.
+        let (image_index, suboptimal, acquire_future) =
+            match swapchain::acquire_next_image(rcx.swapchain.clone(), None)
+                .map_err(Validated::unwrap)
+            {
                 Ok(r) => r,
-                Err(AcquireError::OutOfDate) => {
-                    // window resized / swapchain invalid; recreate later
-                    return;
+                Err(VulkanError::OutOfDate) => {
+                    inst.recreate_swapchain = true;
+                    return Err(RendererError::NeedsRecreate);
                 }
-                Err(e) => panic!("Failed to acquire next image: {e:?}"),
+                Err(e) => return Err(RendererError::Acquire(e)),
             };
 
+        // A suboptimal image still renders, but the swapchain wants rebuilding.
+        if suboptimal {
+            inst.recreate_swapchain = true;
+        }
+
+        // Promote a finished upload to the front buffer. The swap is deferred
+        // until the transfer fence has signalled, so the draw below always binds
+        // a buffer the transfer queue is done writing — no per-frame join needed.
+        inst.vertex_buffers.try_promote();
+
         // 3) Record command buffer: begin render pass with a clear color, then end.
         let framebuffer = rcx.frame_buffers[image_index as usize].clone();
 
@@ -84,48 +738,146 @@ impl Renderer {
             inst.queue.queue_family_index(),
             CommandBufferUsage::OneTimeSubmit,
         )
-        .unwrap();
+        .map_err(|e| RendererError::Record(Box::new(e)))?;
 
         builder
             .begin_render_pass(
                 RenderPassBeginInfo {
-                    clear_values: vec![Some(ClearValue::Float([0.1, 0.1, 0.2, 1.0]))], // bluish
+                    clear_values: vec![Some(ClearValue::Float(inst.clear_color))],
                     ..RenderPassBeginInfo::framebuffer(framebuffer)
                 },
                 SubpassContents::Inline,
             )
-            .unwrap();
+            .map_err(|e| RendererError::Record(Box::new(e)))?;
+
+        // Draw the world points currently in the front buffer, if any.
+        if let Some((vertex_buffer, point_count)) = inst.vertex_buffers.front() {
+            let extent = rcx.swapchain.image_extent();
+            let viewport = Viewport {
+                offset: [0.0, 0.0],
+                extent: [extent[0] as f32, extent[1] as f32],
+                depth_range: 0.0..=1.0,
+            };
 
-        // (Later: bind pipeline + draw here)
+            builder
+                .set_viewport(0, [viewport].into_iter().collect())
+                .map_err(|e| RendererError::Record(Box::new(e)))?
+                .bind_pipeline_graphics(rcx.pipeline.clone())
+                .map_err(|e| RendererError::Record(Box::new(e)))?
+                .bind_vertex_buffers(0, vertex_buffer)
+                .map_err(|e| RendererError::Record(Box::new(e)))?
+                .draw(point_count, 1, 0, 0)
+                .map_err(|e| RendererError::Record(Box::new(e)))?;
+        }
 
-        builder.end_render_pass().unwrap();
+        builder
+            .end_render_pass(SubpassEndInfo::default())
+            .map_err(|e| RendererError::Record(Box::new(e)))?;
 
-        let command_buffer = builder.build().unwrap();
+        let command_buffer = builder
+            .build()
+            .map_err(|e| RendererError::Record(Box::new(e)))?;
 
-        // 4) Submit + present, chaining futures correctly
+        // 4) Submit + present. Vertex uploads are synchronised via the deferred
+        //    swap in `try_promote`, so the frame only has to wait on the image
+        //    acquire and the previous frame's work.
         let previous = inst.previous_frame_end.take().unwrap();
 
         let future = previous
             .join(acquire_future)
             .then_execute(inst.queue.clone(), command_buffer)
-            .unwrap()
+            .map_err(|e| RendererError::Submit(Box::new(e)))?
             .then_swapchain_present(
                 inst.queue.clone(),
                 SwapchainPresentInfo::swapchain_image_index(rcx.swapchain.clone(), image_index),
             )
             .then_signal_fence_and_flush();
 
-        inst.previous_frame_end = Some(match future {
-            Ok(f) => f.boxed(),
-            Err(sync::FlushError::OutOfDate) => {
-                // swapchain became invalid during present; recreate later
-                sync::now(inst.device.clone()).boxed()
+        match future.map_err(Validated::unwrap) {
+            Ok(f) => {
+                inst.previous_frame_end = Some(f.boxed());
+                Ok(())
+            }
+            Err(VulkanError::OutOfDate) => {
+                // swapchain became invalid during present; recreate next frame
+                inst.recreate_swapchain = true;
+                inst.previous_frame_end = Some(vulkano::sync::now(inst.device.clone()).boxed());
+                Err(RendererError::NeedsRecreate)
             }
             Err(e) => {
-                eprintln!("Flush error: {e:?}");
-                sync::now(inst.device.clone()).boxed()
+                inst.previous_frame_end = Some(vulkano::sync::now(inst.device.clone()).boxed());
+                Err(RendererError::Present(e))
             }
-        });
+        }
+    }
+}
+
+// The concrete future produced by flushing a transfer-queue upload with a fence
+// we can poll. Naming it lets `PendingUpload` store it without boxing, so
+// `try_promote` can call `is_signaled`/`wait` on the real type.
+type UploadFuture = FenceSignalFuture<CommandBufferExecFuture<NowFuture>>;
+
+// A vertex-buffer upload recorded on the transfer queue, not yet promoted to the
+// front buffer. `try_promote` swaps `slot` to the front once `future` signals.
+struct PendingUpload {
+    future: UploadFuture,
+    slot: usize,
+    len: u32,
+}
+
+// One of the two long-lived device-local vertex buffers. `buffer` may be larger
+// than `len` points, since it's reused across same-or-smaller uploads.
+struct VertexSlot {
+    buffer: Subbuffer<[Point]>,
+    len: u32,
+}
+
+// Double-buffered vertex storage. `front` is always safe to draw; uploads stream
+// into the back slot and are promoted only after their fence signals, a frame or
+// more after the upload was kicked off.
+#[derive(Default)]
+struct VertexBuffers {
+    slots: [Option<VertexSlot>; 2],
+    front: usize,
+    pending: Option<PendingUpload>,
+}
+
+impl VertexBuffers {
+    // The buffer and point count currently safe to draw, if any.
+    fn front(&self) -> Option<(Subbuffer<[Point]>, u32)> {
+        self.slots[self.front]
+            .as_ref()
+            .filter(|slot| slot.len > 0)
+            .map(|slot| (slot.buffer.clone(), slot.len))
+    }
+
+    fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    // Swap a completed upload into the front buffer. Polls the fence with a zero
+    // timeout so it never blocks; the swap lands on the first frame after the
+    // copy finishes.
+    fn try_promote(&mut self) {
+        let ready = self
+            .pending
+            .as_ref()
+            .is_some_and(|p| p.future.wait(Some(Duration::from_millis(0))).is_ok());
+        if ready {
+            let pending = self.pending.take().unwrap();
+            if let Some(slot) = self.slots[pending.slot].as_mut() {
+                slot.len = pending.len;
+            }
+            self.front = pending.slot;
+        }
+    }
+
+    // Drop all geometry, keeping the buffers allocated for reuse.
+    fn clear(&mut self) {
+        self.pending = None;
+        if let Some(slot) = self.slots[self.front].as_mut() {
+            slot.len = 0;
+        }
     }
 }
 
@@ -134,8 +886,24 @@ struct RenderInstance {
     surface: Arc<Surface>,
     device: Arc<Device>,
     queue: Arc<Queue>,
+    window: Arc<Window>,
     command_buffer_allocator: StandardCommandBufferAllocator,
+    memory_allocator: Arc<StandardMemoryAllocator>,
+    transfer_queue: Arc<Queue>,
+    // Double-buffered vertex storage: uploads stream into the back buffer on the
+    // transfer queue and are promoted to the front a frame later, once complete.
+    vertex_buffers: VertexBuffers,
+    // Geometry loaded from the scene config. When present it takes precedence
+    // over the scene-derived geometry, so hot-reloading the config's point list
+    // is visible instead of being clobbered by the per-frame render system.
+    config_points: Option<Vec<Point>>,
+    // The geometry currently on the GPU, so render() can skip the upload (and
+    // all its allocation / transfer work) on frames where nothing changed.
+    uploaded_points: Option<Vec<Point>>,
+    clear_color: [f32; 4],
+    reload: Option<ReloadWatcher>,
     previous_frame_end: Option<Box<dyn GpuFuture>>,
+    recreate_swapchain: bool,
     rcx: Option<RenderContext>,
 }
 
@@ -143,7 +911,8 @@ struct RenderContext {
     swapchain: Arc<Swapchain>,
     image_views: Vec<Arc<ImageView>>,
     render_pass: Arc<RenderPass>,
-    frame_buffers: Vec<Arc<Framebuffer>>
+    frame_buffers: Vec<Arc<Framebuffer>>,
+    pipeline: Arc<GraphicsPipeline>,
 }
 
 impl RenderContext {
@@ -196,17 +965,86 @@ impl RenderContext {
             .collect();
 
 
+        let pipeline = build_pipeline(device.clone(), render_pass.clone());
+
         RenderContext {
             swapchain,
             image_views,
             render_pass,
-            frame_buffers
+            frame_buffers,
+            pipeline,
         }
     }
+
+    // Rebuild the swapchain (and everything derived from its images) for a new
+    // window size, reusing the existing render pass so pipelines stay valid.
+    // Device-side failures are returned for the caller to classify rather than
+    // panicking mid-resize.
+    fn recreate(&mut self, new_extent: [u32; 2]) -> Result<(), Validated<VulkanError>> {
+        let (new_swapchain, new_images) = self.swapchain.recreate(SwapchainCreateInfo {
+            image_extent: new_extent,
+            ..self.swapchain.create_info()
+        })?;
+
+        self.swapchain = new_swapchain;
+
+        self.image_views = new_images
+            .iter()
+            .map(|img| ImageView::new_default(img.clone()))
+            .collect::<Result<_, _>>()?;
+
+        self.frame_buffers = self
+            .image_views
+            .iter()
+            .map(|view| {
+                Framebuffer::new(
+                    self.render_pass.clone(),
+                    FramebufferCreateInfo {
+                        attachments: vec![view.clone()],
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(())
+    }
+}
+
+// Pick the best available present mode for the requested preference, always
+// falling back to `Fifo` (guaranteed supported) when the surface can't honor it.
+fn select_present_mode(
+    physical_device: &vulkano::device::physical::PhysicalDevice,
+    surface: &Surface,
+    preference: PresentModePreference,
+) -> PresentMode {
+    let available: Vec<PresentMode> = physical_device
+        .surface_present_modes(surface, SurfaceInfo::default())
+        .map(|modes| modes.collect())
+        .unwrap_or_default();
+
+    choose_present_mode(&available, preference)
+}
+
+// Pure selection core: pick the first preferred mode the surface actually offers,
+// falling back to `Fifo` (guaranteed supported) otherwise. Split out from
+// `select_present_mode` so the fallback logic is testable without a device.
+fn choose_present_mode(available: &[PresentMode], preference: PresentModePreference) -> PresentMode {
+    let desired: &[PresentMode] = match preference {
+        PresentModePreference::Vsync => &[PresentMode::Fifo],
+        PresentModePreference::LowLatency => &[PresentMode::Mailbox, PresentMode::Immediate],
+        PresentModePreference::Uncapped => &[PresentMode::Immediate, PresentMode::Mailbox],
+    };
+
+    desired
+        .iter()
+        .copied()
+        .find(|mode| available.contains(mode))
+        .unwrap_or(PresentMode::Fifo)
 }
 
 impl RenderInstance {
-    pub fn new( window: Arc<Window> )-> Self {
+    pub fn new( window: Arc<Window>, present_mode: PresentModePreference )-> Self {
         let instance = {
             let library = VulkanLibrary::new().unwrap();
             let extensions = Surface::required_extensions(window.as_ref()).unwrap();
@@ -267,22 +1105,44 @@ impl RenderInstance {
             })
             .expect("No graphics+present queue family found") as u32;
 
+        // Prefer a dedicated transfer family (one with TRANSFER but distinct from
+        // the graphics family) so uploads run concurrently with rendering. Note
+        // that any GRAPHICS queue implicitly supports transfers, so this is a
+        // best-effort optimization, not a requirement.
+        let transfer_family_index: Option<u32> = physical_device
+            .queue_family_properties()
+            .iter()
+            .enumerate()
+            .position(|(i, q)| {
+                q.queue_flags.contains(QueueFlags::TRANSFER) && i as u32 != queue_family_index
+            })
+            .map(|i| i as u32);
+
+        let mut queue_create_infos = vec![QueueCreateInfo {
+            queue_family_index,
+            ..Default::default()
+        }];
+        if let Some(transfer_family_index) = transfer_family_index {
+            queue_create_infos.push(QueueCreateInfo {
+                queue_family_index: transfer_family_index,
+                ..Default::default()
+            });
+        }
+
         let (device, mut queues) = Device::new(
             physical_device.clone(),
             DeviceCreateInfo {
                 enabled_extensions: device_extensions,
-                queue_create_infos: vec![
-                    QueueCreateInfo {
-                        queue_family_index,
-                        ..Default::default()
-                    }
-                ],
+                queue_create_infos,
                 ..Default::default()
             },
         ).expect("Failed to create logical device");
 
         let queue = queues.next().expect("No queue returned by Device::new");
 
+        // Fall back to the graphics queue when no separate transfer family exists.
+        let transfer_queue = queues.next().unwrap_or_else(|| queue.clone());
+
         let window_size = window.inner_size();
         let image_extent = [window_size.width, window_size.height];
 
@@ -304,13 +1164,18 @@ impl RenderInstance {
         let caps = physical_device.surface_capabilities(&surface, Default::default()).unwrap();
         let composite_alpha = caps.supported_composite_alpha.into_iter().next().unwrap();
 
+        // Honor the caller's latency preference, falling back to Fifo. The chosen
+        // mode is carried on the swapchain's create-info, so recreation reuses it.
+        let present_mode = select_present_mode(&physical_device, &surface, present_mode);
+        println!("using present mode: {present_mode:?}");
+
         let swapchaininfo = SwapchainCreateInfo {
             min_image_count: caps.min_image_count,
             image_format: image_format,
             image_extent,
             image_usage: ImageUsage::COLOR_ATTACHMENT, // we will render into it
             composite_alpha,
-            present_mode: PresentMode::Fifo, // vsync; guaranteed supported
+            present_mode,
             ..Default::default()
         };
 
@@ -325,6 +1190,8 @@ impl RenderInstance {
         let command_buffer_allocator =
             StandardCommandBufferAllocator::new(device.clone(), Default::default());
 
+        let memory_allocator = Arc::new(StandardMemoryAllocator::new_default(device.clone()));
+
         // This future represents “nothing has been submitted yet”, and it’s the standard starting point.
         let previous_frame_end = Some(vulkano::sync::now(device.clone()).boxed());
 
@@ -334,10 +1201,72 @@ impl RenderInstance {
             surface,
             device,
             queue,
+            window,
             command_buffer_allocator,
+            memory_allocator,
+            transfer_queue,
+            vertex_buffers: VertexBuffers::default(),
+            config_points: None,
+            uploaded_points: None,
+            clear_color: [0.1, 0.1, 0.2, 1.0], // bluish
+            reload: None,
             previous_frame_end,
+            recreate_swapchain: false,
             rcx,
         }
 
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn present_mode_prefers_low_latency_when_available() {
+        let available = [PresentMode::Fifo, PresentMode::Mailbox];
+        assert_eq!(
+            choose_present_mode(&available, PresentModePreference::LowLatency),
+            PresentMode::Mailbox,
+        );
+    }
+
+    #[test]
+    fn present_mode_falls_back_to_fifo_when_preference_unsupported() {
+        // Only Fifo is offered, but the caller wanted an uncapped mode.
+        let available = [PresentMode::Fifo];
+        assert_eq!(
+            choose_present_mode(&available, PresentModePreference::Uncapped),
+            PresentMode::Fifo,
+        );
+        // Even with nothing reported, Fifo is the guaranteed fallback.
+        assert_eq!(
+            choose_present_mode(&[], PresentModePreference::LowLatency),
+            PresentMode::Fifo,
+        );
+    }
+
+    #[test]
+    fn scene_config_deserializes_full_entry() {
+        let json = r#"{
+            "shaders_dir": "shaders",
+            "points": [{ "position": [0.0, 0.5], "color": [0.2, 0.4, 0.6] }],
+            "clear_color": [0.0, 0.0, 0.0, 1.0]
+        }"#;
+
+        let config: SceneConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.shaders_dir, PathBuf::from("shaders"));
+        assert_eq!(config.clear_color, [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(config.points.len(), 1);
+        assert_eq!(config.points[0].to_point().color, [0.2, 0.4, 0.6]);
+    }
+
+    #[test]
+    fn config_point_color_defaults_to_white_when_omitted() {
+        let json = r#"{ "position": [1.0, -1.0] }"#;
+        let point: ConfigPoint = serde_json::from_str(json).unwrap();
+
+        assert_eq!(point.color, default_point_color());
+        assert_eq!(point.to_point().position, [1.0, -1.0]);
+    }
 }
\ No newline at end of file
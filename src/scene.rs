@@ -0,0 +1,128 @@
+//! A minimal component store decoupling world state from the GPU backend.
+//!
+//! Entities are plain indices into parallel component columns. It is far from a
+//! full ECS, but it gives the `App` a place to hold gameplay/world state while
+//! the `Renderer` consumes the result each frame via a render system.
+
+/// Opaque handle to an entity in a [`Scene`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entity(usize);
+
+/// A point's clip-space position.
+#[derive(Clone, Copy, Debug)]
+pub struct Position {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// An optional per-entity RGB color.
+#[derive(Clone, Copy, Debug)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+/// Component columns indexed by [`Entity`].
+///
+/// Every entity has a `Position`; `Color` is optional and `Renderable` is a
+/// tag that gates whether the render system emits a vertex for it.
+pub struct Scene {
+    positions: Vec<Position>,
+    colors: Vec<Option<Color>>,
+    renderable: Vec<bool>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        Self {
+            positions: Vec::new(),
+            colors: Vec::new(),
+            renderable: Vec::new(),
+        }
+    }
+
+    /// Spawn an entity at `position`. It starts with no color and is not
+    /// renderable until tagged via [`Scene::set_renderable`].
+    pub fn spawn(&mut self, position: Position) -> Entity {
+        let id = self.positions.len();
+        self.positions.push(position);
+        self.colors.push(None);
+        self.renderable.push(false);
+        Entity(id)
+    }
+
+    /// Attach (or replace) the color of an entity.
+    pub fn set_color(&mut self, entity: Entity, color: Color) {
+        self.colors[entity.0] = Some(color);
+    }
+
+    /// Toggle whether an entity is drawn.
+    pub fn set_renderable(&mut self, entity: Entity, renderable: bool) {
+        self.renderable[entity.0] = renderable;
+    }
+
+    /// Iterate the renderable entities and their optional color — the input a
+    /// render system folds into vertices.
+    pub fn renderables(&self) -> impl Iterator<Item = (&Position, Option<&Color>)> {
+        (0..self.positions.len())
+            .filter(move |&i| self.renderable[i])
+            .map(move |i| (&self.positions[i], self.colors[i].as_ref()))
+    }
+}
+
+impl Default for Scene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawn_assigns_sequential_handles_and_starts_hidden() {
+        let mut scene = Scene::new();
+        let a = scene.spawn(Position { x: 0.0, y: 0.0 });
+        let b = scene.spawn(Position { x: 1.0, y: 1.0 });
+
+        assert_eq!(a, Entity(0));
+        assert_eq!(b, Entity(1));
+        // Nothing is renderable until explicitly tagged.
+        assert_eq!(scene.renderables().count(), 0);
+    }
+
+    #[test]
+    fn set_renderable_gates_the_render_system() {
+        let mut scene = Scene::new();
+        let e = scene.spawn(Position { x: 0.5, y: -0.5 });
+
+        scene.set_renderable(e, true);
+        assert_eq!(scene.renderables().count(), 1);
+
+        scene.set_renderable(e, false);
+        assert_eq!(scene.renderables().count(), 0);
+    }
+
+    #[test]
+    fn renderables_carries_position_and_optional_color() {
+        let mut scene = Scene::new();
+        let plain = scene.spawn(Position { x: 0.0, y: 0.0 });
+        let colored = scene.spawn(Position { x: 1.0, y: 2.0 });
+        scene.set_renderable(plain, true);
+        scene.set_renderable(colored, true);
+        scene.set_color(colored, Color { r: 1.0, g: 0.0, b: 0.0 });
+
+        let collected: Vec<_> = scene.renderables().collect();
+        assert_eq!(collected.len(), 2);
+
+        let (pos, color) = collected[0];
+        assert_eq!((pos.x, pos.y), (0.0, 0.0));
+        assert!(color.is_none());
+
+        let (pos, color) = collected[1];
+        assert_eq!((pos.x, pos.y), (1.0, 2.0));
+        assert_eq!(color.map(|c| (c.r, c.g, c.b)), Some((1.0, 0.0, 0.0)));
+    }
+}